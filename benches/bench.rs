@@ -45,6 +45,22 @@ fn bench_populating_push(c: &mut Criterion) {
     ]);
 }
 
+fn bench_populating_extend(c: &mut Criterion) {
+    c.bench_function_over_inputs("populating items (bulk extend)", |b, (items, metas)| {
+        let items = random_items(*metas).take(*items).collect::<Vec<_>>();
+        b.iter_with_setup(|| items.clone(), |items| {
+            let mut store = Store::new();
+            store.extend(items);
+            store
+        })
+    }, &[
+        (100, 10),
+        (100, 100),
+        (1000, 10),
+        (1000, 100),
+    ]);
+}
+
 fn bench_single_push(c: &mut Criterion) {
     c.bench_function_over_inputs("single push", |b, (items, metas)| {
         let mut items = random_items(*metas).take(*items).collect::<Vec<_>>();
@@ -121,6 +137,6 @@ fn bench_split_half_size(c: &mut Criterion) {
     ]);
 }
 
-criterion_group!(benches, bench_populating_push, bench_append_meta, bench_single_push, bench_split_median_price, bench_split_half_size);
+criterion_group!(benches, bench_populating_push, bench_populating_extend, bench_append_meta, bench_single_push, bench_split_median_price, bench_split_half_size);
 //criterion_group!(benches, bench_split_median_price, bench_split_half_size);
 criterion_main!(benches);
@@ -1,5 +1,9 @@
 pub mod optimized_vec;
 use std::cmp::min;
+use std::iter::FromIterator;
+use std::ops::{Bound, RangeBounds};
+
+pub use optimized_vec::TryReserveError;
 
 pub type Price = i32;
 pub type Size = u32;
@@ -29,6 +33,73 @@ impl Store {
         self.inner.insert(idx, elem);
     }
 
+    // O(n)
+    // Fallible `insert`: pre-reserves the slot so the store is left unmodified
+    // if the allocator cannot grow the backing storage. `try_reserve` compacts
+    // and secures room for one more element, and a front insert (lowest price,
+    // `idx == 0`) reuses that reserved slot with a plain shift rather than
+    // opening fresh front headroom, so no insertion reaches an infallible grow.
+    pub fn try_insert(&mut self, elem: (Price, Container<(Size, Meta)>)) -> Result<(), TryReserveError> {
+        let idx = match self.find_price_idx(elem.0) {
+            | Ok(idx)
+            | Err(idx) => idx
+        };
+        self.inner.try_reserve(1)?;
+        self.inner.insert(idx, elem);
+        Ok(())
+    }
+
+    // O(n + k * log(k))
+    // n -- existing price levels, k -- incoming items
+    // Bulk-inserts `items` in a single merge pass instead of `k` separate
+    // O(n) `insert` calls. The batch is sorted, entries sharing a price are
+    // concatenated, and the result is interleaved against the existing ladder.
+    pub fn insert_many<I>(&mut self, items: I)
+        where I: IntoIterator<Item = (Price, Container<(Size, Meta)>)>
+    {
+        // Collect and sort the incoming batch; `sort_by_key` is stable, so the
+        // arrival order of equal-priced items (and thus their metadata) is kept.
+        let mut batch: Vec<(Price, Container<(Size, Meta)>)> = items.into_iter().collect();
+        batch.sort_by_key(|item| item.0);
+
+        // Fold equal-priced neighbours into one level, concatenating their
+        // size/meta lists in arrival order.
+        let mut merged: Vec<(Price, Container<(Size, Meta)>)> = Vec::with_capacity(batch.len());
+        for (price, sizes) in batch {
+            match merged.last_mut() {
+                Some(last) if last.0 == price => {
+                    for sm in sizes {
+                        last.1.push(sm);
+                    }
+                }
+                _ => merged.push((price, sizes)),
+            }
+        }
+
+        // Interleave-merge the batch against the current ladder in one O(n + k)
+        // pass. Existing levels sort before an equal incoming price, so a new
+        // level lands after the equal ones already there.
+        let existing = std::mem::replace(&mut self.inner, Container::new());
+        let mut out = Vec::with_capacity(existing.len() + merged.len());
+        let mut ex = existing.into_iter().peekable();
+        let mut inc = merged.into_iter().peekable();
+        loop {
+            match (ex.peek(), inc.peek()) {
+                (Some(e), Some(i)) => {
+                    if e.0 <= i.0 {
+                        out.push(ex.next().unwrap());
+                    } else {
+                        out.push(inc.next().unwrap());
+                    }
+                }
+                (Some(_), None) => out.push(ex.next().unwrap()),
+                (None, Some(_)) => out.push(inc.next().unwrap()),
+                (None, None) => break,
+            }
+        }
+        self.inner = Container::from(out);
+    }
+
     // O(log(n))
     pub fn append_size_and_meta_to_price(&mut self, price: Price, meta: (Size, Meta)) {
         let idx = match self.find_price_idx(price) {
@@ -38,23 +109,63 @@ impl Store {
         self.inner[idx].1.push(meta);
     }
 
+    // O(log(n))
+    // Fallible `append_size_and_meta_to_price`: reserves room for the new chunk
+    // before pushing so the price level is left untouched on allocation failure.
+    pub fn try_append_size_and_meta_to_price(&mut self, price: Price, meta: (Size, Meta)) -> Result<(), TryReserveError> {
+        let idx = match self.find_price_idx(price) {
+            Ok(idx) => idx,
+            Err(_) => panic!("price {} does not exist in Store", price),
+        };
+        self.inner[idx].1.try_reserve(1)?;
+        self.inner[idx].1.push(meta);
+        Ok(())
+    }
+
+    // O(log(n) + k)
+    // Iterates the price levels whose price falls within `range`, honoring the
+    // `Included`/`Excluded`/`Unbounded` endpoints. Both ends are resolved with
+    // a binary search, so the scan is O(log n) to locate the window plus O(k)
+    // over the k matching levels.
+    pub fn range<R: RangeBounds<Price>>(&self, range: R) -> impl Iterator<Item = &(Price, Container<(Size, Meta)>)> {
+        let start = self.resolve_lower(range.start_bound());
+        let end = self.resolve_upper(range.end_bound());
+        self.inner.iter_range(start, end)
+    }
+
+    // O(log(n) + k)
+    // Mutable counterpart to [`range`](Self::range).
+    pub fn range_mut<R: RangeBounds<Price>>(&mut self, range: R) -> impl Iterator<Item = &mut (Price, Container<(Size, Meta)>)> {
+        let start = self.resolve_lower(range.start_bound());
+        let end = self.resolve_upper(range.end_bound());
+        self.inner.iter_range_mut(start, end)
+    }
+
+    // First index whose price satisfies the lower `bound`.
+    fn resolve_lower(&self, bound: Bound<&Price>) -> usize {
+        match bound {
+            Bound::Unbounded => 0,
+            Bound::Included(&price) => self.inner.partition_point(|elem| elem.0 < price),
+            Bound::Excluded(&price) => self.inner.partition_point(|elem| elem.0 <= price),
+        }
+    }
+
+    // Exclusive index one past the last price satisfying the upper `bound`.
+    fn resolve_upper(&self, bound: Bound<&Price>) -> usize {
+        match bound {
+            Bound::Unbounded => self.inner.len(),
+            Bound::Included(&price) => self.inner.partition_point(|elem| elem.0 <= price),
+            Bound::Excluded(&price) => self.inner.partition_point(|elem| elem.0 < price),
+        }
+    }
+
     // O(n * m)
     // n -- number of prices
     // m -- number of metadata chunks attached to each price
     pub fn split(&mut self, max_price: Price, mut requested_size: Size) -> Store {
-        // Get an length of part of the prices array with prices < max_price
-        let mut upper_bound = match self.find_price_idx(max_price) {
-            Ok(idx) => {
-                // if found, there might be several equal prices, need the last one
-                self.inner[idx..].iter()
-                    .enumerate()
-                    .take_while(|(_, (price, _))| *price <= max_price)
-                    .last()
-                    .map(|(idx, _)| idx + 1)
-                    .unwrap()
-            },
-            Err(idx) => min(idx, self.inner.len()),
-        };
+        // Length of the prefix of price levels with price <= max_price; the
+        // inclusive/exclusive handling of equal prices lives in `resolve_upper`.
+        let mut upper_bound = self.resolve_upper(Bound::Included(&max_price));
 
         let mut new = Container::with_capacity(self.inner.len());
 
@@ -80,6 +191,15 @@ impl Store {
         Store::from(new)
     }
 
+    // O(n): the levels above `max_price` are shifted to the front when the
+    // returned iterator is dropped.
+    // Lazily removes and yields every price level at or below `max_price` from
+    // the front of the ladder, in ascending price order.
+    pub fn drain_prices(&mut self, max_price: Price) -> impl Iterator<Item = (Price, Container<(Size, Meta)>)> + '_ {
+        let upper_bound = self.resolve_upper(Bound::Included(&max_price));
+        self.inner.drain(0..upper_bound)
+    }
+
     /// Returns if sizes of this sizes list was drained, produced sizes list
     /// and how much more volume is left to move to the new vector
     fn split_sizes(&mut self, price_idx: usize, mut requested: u32) -> (bool, SizeMetaList, u32) {
@@ -121,6 +241,20 @@ impl From<StoreInner> for Store {
     }
 }
 
+impl Extend<(Price, Container<(Size, Meta)>)> for Store {
+    fn extend<I: IntoIterator<Item = (Price, Container<(Size, Meta)>)>>(&mut self, iter: I) {
+        self.insert_many(iter);
+    }
+}
+
+impl FromIterator<(Price, Container<(Size, Meta)>)> for Store {
+    fn from_iter<I: IntoIterator<Item = (Price, Container<(Size, Meta)>)>>(iter: I) -> Self {
+        let mut store = Store::new();
+        store.insert_many(iter);
+        store
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,5 +316,106 @@ mod tests {
         check_split((orig, new), (orig_expected, new_expected));
     }
 
+    #[test]
+    fn range_honors_bound_endpoints() {
+        use std::ops::Bound::*;
+        let store = Store::from(Container::from(vec![
+            (5, Container::from(vec![(1, 0)])),
+            (7, Container::from(vec![(1, 0)])),
+            (9, Container::from(vec![(1, 0)])),
+        ]));
+
+        // Half-open excludes the upper endpoint ...
+        assert_eq!(store.range(5..9).map(|(p, _)| *p).collect::<Vec<_>>(), vec![5, 7]);
+        // ... inclusive keeps it ...
+        assert_eq!(store.range(5..=9).map(|(p, _)| *p).collect::<Vec<_>>(), vec![5, 7, 9]);
+        // ... and an explicit exclusive lower endpoint drops equal prices.
+        assert_eq!(
+            store.range((Excluded(5), Included(9))).map(|(p, _)| *p).collect::<Vec<_>>(),
+            vec![7, 9]
+        );
+        assert_eq!(store.range(..).map(|(p, _)| *p).collect::<Vec<_>>(), vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn range_mut_touches_only_the_window() {
+        let mut store = Store::from(Container::from(vec![
+            (5, Container::from(vec![(1, 0)])),
+            (7, Container::from(vec![(1, 0)])),
+        ]));
+
+        for (_, sizes) in store.range_mut(..7) {
+            sizes.push((99, 0));
+        }
+
+        assert_eq!(store.inner[0].1.len(), 2); // price 5 is inside `..7`
+        assert_eq!(store.inner[1].1.len(), 1); // price 7 is excluded
+    }
+
+    #[test]
+    fn try_insert_places_in_price_order() {
+        let mut store = make_initial();
+        store.try_insert((6, Container::from(vec![(1, 0)]))).unwrap();
+        assert_eq!(store.inner.len(), 3);
+        assert_eq!(store.inner[0].0, 5);
+        assert_eq!(store.inner[1].0, 6);
+        assert_eq!(store.inner[2].0, 7);
+    }
+
+    #[test]
+    fn try_insert_new_lowest_price() {
+        // A new lowest price resolves to index 0; the fallible path must reserve
+        // the slot and land it there without reaching an infallible front grow.
+        let mut store = make_initial(); // prices 5, 7
+        store.try_insert((3, Container::from(vec![(1, 0)]))).unwrap();
+        assert_eq!(store.inner.iter().map(|(p, _)| *p).collect::<Vec<_>>(), vec![3, 5, 7]);
+        assert_eq!(store.inner[0].1.len(), 1);
+    }
+
+    #[test]
+    fn try_append_adds_meta_to_existing_price() {
+        let mut store = make_initial();
+        store.try_append_size_and_meta_to_price(5, (99, 7)).unwrap();
+        assert_eq!(store.inner[0].1.len(), 3);
+    }
+
+    #[test]
+    fn insert_many_merges_and_sorts() {
+        let mut store = make_initial(); // prices 5, 7
+        store.insert_many(vec![
+            (9, Container::from(vec![(1, 0)])),
+            (3, Container::from(vec![(2, 0)])),
+            // Two incoming items at the same price are concatenated into one
+            // level, in arrival order.
+            (6, Container::from(vec![(3, 0)])),
+            (6, Container::from(vec![(4, 0)])),
+        ]);
+        assert_eq!(store.inner.iter().map(|(p, _)| *p).collect::<Vec<_>>(), vec![3, 5, 6, 7, 9]);
+        let six = &store.inner[2];
+        assert_eq!(six.0, 6);
+        assert_eq!(six.1.iter().map(|(s, _)| *s).collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn extend_keeps_existing_before_equal_incoming_price() {
+        let mut store = make_initial(); // price 7 already present
+        store.extend(vec![(7, Container::from(vec![(99, 0)]))]);
+        // The new level sits after the pre-existing one at the same price.
+        assert_eq!(store.inner.iter().map(|(p, _)| *p).collect::<Vec<_>>(), vec![5, 7, 7]);
+        assert_eq!(store.inner[1].1.len(), 2); // the original price-7 level
+        assert_eq!(store.inner[2].1.len(), 1); // the extended one
+    }
+
+    #[test]
+    fn from_iter_collects_into_sorted_store() {
+        let store: Store = vec![
+            (7, Container::from(vec![(1, 0)])),
+            (5, Container::from(vec![(2, 0)])),
+            (5, Container::from(vec![(3, 0)])),
+        ].into_iter().collect();
+        assert_eq!(store.inner.iter().map(|(p, _)| *p).collect::<Vec<_>>(), vec![5, 7]);
+        assert_eq!(store.inner[0].1.iter().map(|(s, _)| *s).collect::<Vec<_>>(), vec![2, 3]);
+    }
+
 }
 
@@ -1,7 +1,34 @@
 use std::fmt::{self, Debug};
-use std::ops::{Index, IndexMut};
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+use std::ptr;
+
+/// Reason a fallible reservation could not be satisfied.
+///
+/// Mirrors the split the standard library draws so callers can tell a request
+/// that can never succeed apart from a transient allocator shortage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested capacity overflows `usize`, or its byte size overflows
+    /// the `isize::MAX` the allocator is allowed to hand out.
+    CapacityOverflow,
+    /// The allocator refused a block large enough for the request.
+    AllocError,
+}
 
-#[derive(Clone)]
+/// A double-ended buffer that keeps cheap O(1) pushes and pops at *both* ends.
+///
+/// The live elements occupy the logical window `inner[first..]`. Removals and
+/// pops from the front simply advance `first`, so `inner[0..first]` is spare
+/// capacity that has been *moved out of* -- those slots are uninitialized and
+/// are reclaimed lazily by `compact`. Pushing at the front fills those spare
+/// slots in place; when none are left the window is slid up within the buffer
+/// (growing the allocation if needed) to open a fresh, geometrically sized run
+/// of front capacity, so `push_front` is amortized O(1) just like the back.
+/// Because the window stays contiguous, `binary_search_by_key`, `Index` and
+/// iteration keep working over a plain slice. Growth at the back goes through
+/// `Vec`, so pushes there are amortized O(1) as well.
 pub struct OptimizedVec<T> {
     first: usize,
     inner: Vec<T>
@@ -22,6 +49,31 @@ impl<T: PartialEq> PartialEq for OptimizedVec<T> {
     }
 }
 
+impl<T: Clone> Clone for OptimizedVec<T> {
+    fn clone(&self) -> Self {
+        // Only the live window is initialized; clone it into a gap-free buffer
+        // so we never read the moved-out front slots.
+        OptimizedVec {
+            first: 0,
+            inner: self.inner[self.first..].to_vec(),
+        }
+    }
+}
+
+impl<T> Drop for OptimizedVec<T> {
+    fn drop(&mut self) {
+        // The orphaned front is uninitialized; drop only the live window and
+        // then hand an empty `Vec` back so it frees the buffer without touching
+        // the moved-out slots.
+        let live = self.inner.len() - self.first;
+        unsafe {
+            let window = self.inner.as_mut_ptr().add(self.first);
+            self.inner.set_len(0);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(window, live));
+        }
+    }
+}
+
 impl<T> OptimizedVec<T> {
     pub fn new() -> Self {
         OptimizedVec {
@@ -39,37 +91,137 @@ impl<T> OptimizedVec<T> {
 
     #[inline]
     pub fn push(&mut self, value: T) {
-        self.inner.push(value)
+        self.push_back(value)
+    }
+
+    /// Appends `value` after the last element in amortized O(1).
+    #[inline]
+    pub fn push_back(&mut self, value: T) {
+        // A reallocation would copy the whole `[0..len]` span, including the
+        // uninitialized front; compact it away first so only live elements move.
+        if self.inner.len() == self.inner.capacity() {
+            self.compact();
+        }
+        self.inner.push(value);
+    }
+
+    /// Removes and returns the last element, or `None` when empty, in O(1).
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.inner.pop()
+    }
+
+    /// Prepends `value` before the first element in amortized O(1), reusing a
+    /// moved-out front slot when one is available.
+    #[inline]
+    pub fn push_front(&mut self, value: T) {
+        if self.first == 0 {
+            // No front gap left: open a fresh run of front capacity.
+            self.grow_front();
+        }
+        self.first -= 1;
+        unsafe {
+            // The reclaimed slot is uninitialized spare capacity, so write
+            // into it rather than assigning (which would drop a non-value).
+            ptr::write(self.inner.as_mut_ptr().add(self.first), value);
+        }
+    }
+
+    // Slides the live window up within the buffer to open spare slots at the
+    // front, growing the allocation when it can't hold the shift. The headroom
+    // is proportional to the current length, so a run of front pushes amortizes
+    // to O(1) the same way `Vec`'s geometric back growth does.
+    #[cold]
+    fn grow_front(&mut self) {
+        // With `first == 0` the physical length equals the live length.
+        let len = self.inner.len();
+        let headroom = len.max(1);
+        self.inner.reserve(headroom);
+        unsafe {
+            let ptr = self.inner.as_mut_ptr();
+            // Detach the window before the move so a panic can't double-free,
+            // then slide every live element up by `headroom`.
+            self.inner.set_len(0);
+            ptr::copy(ptr, ptr.add(headroom), len);
+            self.inner.set_len(len + headroom);
+        }
+        self.first = headroom;
+    }
+
+    /// Removes and returns the first element, or `None` when empty, in O(1).
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let value = unsafe { ptr::read(self.inner.as_ptr().add(self.first)) };
+        self.first += 1;
+        self.reclaim_front();
+        Some(value)
+    }
+
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.inner.get(self.first)
+    }
+
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.inner.last()
+        }
     }
 
     #[inline]
     pub fn insert(&mut self, idx: usize, value: T) {
-        // likely branch goes first
-        if !self.inner.is_empty() {
-            if idx == 0 && self.first != 0 {
-                self.first -= 1;
-                self.inner[self.first] = value;
-            } else {
-                self.inner.insert(idx, value);
+        // A front insert reuses a moved-out slot in place when one is available,
+        // keeping the window contiguous. Unlike `push_front` it never opens a
+        // fresh run of front headroom, so a gap-less front insert stays a plain
+        // shift and leaves `first` at 0 for positional callers.
+        if idx == 0 && self.first != 0 {
+            self.first -= 1;
+            unsafe {
+                // The reclaimed slot is uninitialized spare capacity, so write
+                // into it rather than assigning (which would drop a non-value).
+                ptr::write(self.inner.as_mut_ptr().add(self.first), value);
             }
-        } else {
-            self.inner.insert(idx, value);
+            return;
         }
+        // A middle/back insert (or a gap-less front insert) can reallocate; drop
+        // the uninitialized front beforehand so the growth copy never reads a
+        // moved-out slot.
+        if self.inner.len() == self.inner.capacity() {
+            self.compact();
+        }
+        self.inner.insert(self.first + idx, value);
     }
 
     #[inline]
     pub fn remove(&mut self, idx: usize) {
-        assert!(self.len() != 0);
+        assert!(!self.is_empty());
 
         // likely branch goes first
-        if !self.inner.is_empty() {
-            if idx == 0 {
-                self.first += 1;
-            } else {
-                self.inner.remove(self.first + idx);
+        if idx == 0 {
+            unsafe {
+                ptr::drop_in_place(self.inner.as_mut_ptr().add(self.first));
             }
+            self.first += 1;
+            self.reclaim_front();
         } else {
-            self.inner.remove(idx);
+            self.inner.remove(self.first + idx);
+        }
+    }
+
+    // Reclaims the moved-out front once it outgrows the live window.
+    #[inline]
+    fn reclaim_front(&mut self) {
+        if self.first > self.inner.len() / 2 {
+            self.compact();
         }
     }
 
@@ -107,6 +259,148 @@ impl<T> OptimizedVec<T> {
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.inner[self.first..].iter()
     }
+
+    #[inline]
+    pub fn partition_point<P>(&self, pred: P) -> usize
+        where P: FnMut(&T) -> bool
+    {
+        self.inner[self.first..].partition_point(pred)
+    }
+
+    #[inline]
+    pub fn iter_range(&self, start: usize, end: usize) -> impl Iterator<Item = &T> {
+        // A crossed window yields nothing, mirroring a reversed `Range`.
+        let base = self.first;
+        let end = end.max(start);
+        self.inner[base + start..base + end].iter()
+    }
+
+    #[inline]
+    pub fn iter_range_mut(&mut self, start: usize, end: usize) -> impl Iterator<Item = &mut T> {
+        // A crossed window yields nothing, mirroring a reversed `Range`.
+        let base = self.first;
+        let end = end.max(start);
+        self.inner[base + start..base + end].iter_mut()
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        // Compact first: a reallocation copies `[0..len]`, so the front must not
+        // still hold uninitialized slots when the buffer grows.
+        self.compact();
+        self.inner.reserve(additional);
+    }
+
+    #[inline]
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.compact();
+        self.inner.reserve_exact(additional);
+    }
+
+    /// Fallible counterpart to [`reserve`](Self::reserve): returns the error
+    /// instead of aborting the process when growth cannot be satisfied, leaving
+    /// the vector untouched.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        // Catch the overflow cases ourselves so we can report them distinctly
+        // from a genuine allocator refusal. Nothing is mutated yet, so a
+        // rejected request returns with the structure untouched.
+        let required = self.inner.len()
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+        required
+            .checked_mul(mem::size_of::<T>())
+            .filter(|bytes| *bytes <= isize::MAX as usize)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        // Reserve before touching our own layout: if the allocator refuses,
+        // `inner` is left exactly as it was and the call is a true no-op. Only
+        // once growth has succeeded do we reclaim the abandoned front.
+        self.inner
+            .try_reserve(additional)
+            .map_err(|_| TryReserveError::AllocError)?;
+        self.compact();
+        Ok(())
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        // Drop the abandoned front first so we don't keep paying for it.
+        self.compact();
+        self.inner.shrink_to_fit();
+    }
+
+    /// Removes the logical `range` and returns a [`Drain`] yielding the removed
+    /// elements in order. Like `Vec::drain`, the source is repaired when the
+    /// iterator is dropped -- any elements left unconsumed are dropped and the
+    /// surviving tail is shifted back into place.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "drain start is greater than end");
+        assert!(end <= len, "drain end is out of bounds");
+
+        let phys_start = self.first + start;
+        let phys_end = self.first + end;
+        let full_len = self.inner.len();
+        unsafe {
+            // Detach everything from `phys_start` onwards: the `Vec` temporarily
+            // owns only `inner[..phys_start]` so a panic or early drop cannot
+            // double-free the drained range or the tail.
+            self.inner.set_len(phys_start);
+            let range_slice = std::slice::from_raw_parts(
+                self.inner.as_ptr().add(phys_start),
+                end - start,
+            );
+            Drain {
+                tail_start: phys_end,
+                tail_len: full_len - phys_end,
+                iter: range_slice.iter(),
+                inner: ptr::NonNull::from(&mut self.inner),
+                _marker: PhantomData,
+            }
+        }
+    }
+
+    /// Slides the live window down to index `0` over the moved-out
+    /// `inner[0..first]` slots (which hold no values and are not dropped),
+    /// releasing the wasted front without touching the backing allocation's
+    /// capacity.
+    fn compact(&mut self) {
+        if self.first == 0 {
+            return;
+        }
+
+        let live = self.inner.len() - self.first;
+        unsafe {
+            let ptr = self.inner.as_mut_ptr();
+            // The orphaned front `[0..first]` is moved-out spare capacity, so it
+            // needs no destruction -- just slide the live window down over it.
+            // Detach from the `Vec` first so a panic cannot double-free.
+            self.inner.set_len(0);
+            ptr::copy(ptr.add(self.first), ptr, live);
+            self.inner.set_len(live);
+        }
+        self.first = 0;
+    }
+}
+
+impl<T> Default for OptimizedVec<T> {
+    fn default() -> Self {
+        OptimizedVec::new()
+    }
 }
 
 impl<T> From<Vec<T>> for OptimizedVec<T> {
@@ -118,6 +412,75 @@ impl<T> From<Vec<T>> for OptimizedVec<T> {
     }
 }
 
+impl<T> IntoIterator for OptimizedVec<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(mut self) -> Self::IntoIter {
+        // Slide the live window to index 0, then move the backing `Vec` out
+        // without running our `Drop` (which would destruct the window).
+        self.compact();
+        let this = mem::ManuallyDrop::new(self);
+        let inner = unsafe { ptr::read(&this.inner) };
+        inner.into_iter()
+    }
+}
+
+/// A draining iterator returned by [`OptimizedVec::drain`].
+///
+/// Yields the removed elements in order; on drop it runs the destructor of any
+/// element that was not consumed and slides the surviving tail back over the
+/// drained region, leaving the source in a valid state.
+pub struct Drain<'a, T: 'a> {
+    /// Physical index where the untouched tail begins.
+    tail_start: usize,
+    /// Number of still-live elements after the drained range.
+    tail_len: usize,
+    iter: std::slice::Iter<'a, T>,
+    inner: ptr::NonNull<Vec<T>>,
+    _marker: PhantomData<&'a mut OptimizedVec<T>>,
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.iter.next().map(|elem| unsafe { ptr::read(elem) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.iter.next_back().map(|elem| unsafe { ptr::read(elem) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        // Drop every element the caller did not take.
+        for _ in self.by_ref() {}
+
+        unsafe {
+            let inner = self.inner.as_mut();
+            let start = inner.len(); // == phys_start detached in `drain`
+            if self.tail_len > 0 {
+                let ptr = inner.as_mut_ptr();
+                ptr::copy(ptr.add(self.tail_start), ptr.add(start), self.tail_len);
+            }
+            inner.set_len(start + self.tail_len);
+        }
+    }
+}
+
 impl<T> Index<usize> for OptimizedVec<T> {
     type Output = T;
     #[inline]
@@ -136,6 +499,22 @@ impl<T> IndexMut<usize> for OptimizedVec<T> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    struct DropCounter {
+        count: Rc<Cell<usize>>,
+    }
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.count.set(self.count.get() + 1);
+        }
+    }
+
+    fn counters(count: &Rc<Cell<usize>>, n: usize) -> Vec<DropCounter> {
+        (0..n).map(|_| DropCounter { count: count.clone() }).collect()
+    }
 
     #[test]
     fn from() {
@@ -183,28 +562,55 @@ mod tests {
     }
 
     #[test]
-    fn insert_shifted_head() {
+    fn push_front_reuses_moved_out_slots() {
         let mut o = OptimizedVec::from(vec![1, 2, 3, 4, 5]);
-        o.first = 2;
-        assert_eq!(o.inner.len(), 5);
-        assert_eq!(o.len(), 3);
+        // Popping the front opens reusable spare slots in `inner[..first]`.
+        assert_eq!(o.pop_front(), Some(1));
+        assert_eq!(o.pop_front(), Some(2));
         assert_eq!(o.first, 2);
-        o.insert(3, 3);
-        assert_eq!(o.inner.len(), 6);
-        assert_eq!(o.len(), 4);
-        assert_eq!(o.first, 2);
-        o.insert(1, 1);
-        assert_eq!(o.inner.len(), 7);
-        assert_eq!(o.len(), 5);
-        assert_eq!(o.first, 2);
-        o.insert(0, 0);
-        assert_eq!(o.inner.len(), 7);
-        assert_eq!(o.len(), 6);
+        assert_eq!(o.len(), 3);
+        // push_front fills the gap in place -- no shift, no growth.
+        o.push_front(20);
         assert_eq!(o.first, 1);
-        o.insert(0, 0);
-        assert_eq!(o.inner.len(), 7);
-        assert_eq!(o.len(), 7);
+        o.push_front(10);
         assert_eq!(o.first, 0);
+        assert_eq!(o.len(), 5);
+        assert_eq!(o.iter().cloned().collect::<Vec<_>>(), vec![10, 20, 3, 4, 5]);
+    }
+
+    #[test]
+    fn push_front_from_empty_grows_front_capacity() {
+        // A pure front-push workload must open fresh front capacity instead of
+        // shifting the whole buffer on every call.
+        let mut o = OptimizedVec::new();
+        for i in 0..8 {
+            o.push_front(i);
+        }
+        assert_eq!(o.len(), 8);
+        assert_eq!(
+            o.iter().cloned().collect::<Vec<_>>(),
+            vec![7, 6, 5, 4, 3, 2, 1, 0]
+        );
+        assert!(o.capacity() >= o.len());
+        // The window stays contiguous, so ordered lookups keep working.
+        assert_eq!(o.binary_search_by_key(&4, |v| 7 - *v), Ok(4));
+    }
+
+    #[test]
+    fn push_pop_both_ends() {
+        let mut o = OptimizedVec::new();
+        assert_eq!(o.pop_front(), None);
+        assert_eq!(o.pop_back(), None);
+        o.push_back(2);
+        o.push_back(3);
+        o.push_front(1);
+        o.push_front(0);
+        assert_eq!(o.front(), Some(&0));
+        assert_eq!(o.back(), Some(&3));
+        assert_eq!(o.iter().cloned().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(o.pop_front(), Some(0));
+        assert_eq!(o.pop_back(), Some(3));
+        assert_eq!(o.iter().cloned().collect::<Vec<_>>(), vec![1, 2]);
     }
 
     #[test]
@@ -232,10 +638,11 @@ mod tests {
         assert_eq!(o.inner.len(), 3);
         assert_eq!(o.len(), 2);
         assert_eq!(o.first, 1);
+        // `first` now passes `inner.len() / 2`, so this front remove compacts.
         o.remove(0);
-        assert_eq!(o.inner.len(), 3);
+        assert_eq!(o.inner.len(), 1);
         assert_eq!(o.len(), 1);
-        assert_eq!(o.first, 2);
+        assert_eq!(o.first, 0);
 
     }
 
@@ -244,9 +651,7 @@ mod tests {
     fn remove_all_shifted() {
         let mut o = OptimizedVec::from(vec![1]);
         o.remove(0);
-        assert_eq!(o.inner.len(), 1);
         assert_eq!(o.len(), 0);
-        assert_eq!(o.first, 1);
         o.remove(0);
     }
 
@@ -254,22 +659,129 @@ mod tests {
     fn combined() {
         let mut o = OptimizedVec::new();
         o.push(1);
-        assert_eq!(o.inner.len(), 1);
         assert_eq!(o.len(), 1);
         assert_eq!(o.first, 0);
+        // Emptying the front compacts the lone element away.
         o.remove(0);
-        assert_eq!(o.inner.len(), 1);
         assert_eq!(o.len(), 0);
-        assert_eq!(o.first, 1);
+        assert_eq!(o.first, 0);
         o.push(1);
-        assert_eq!(o.inner.len(), 2);
+        o.push(2);
+        assert_eq!(o.len(), 2);
+        o.pop_front();
         assert_eq!(o.len(), 1);
         assert_eq!(o.first, 1);
-        o.insert(0, 0);
-        assert_eq!(o.inner.len(), 2);
+        // The freed front slot is reused by the next push_front.
+        o.push_front(0);
         assert_eq!(o.len(), 2);
         assert_eq!(o.first, 0);
+        assert_eq!(o.iter().cloned().collect::<Vec<_>>(), vec![0, 2]);
     }
 
+    #[test]
+    fn compaction_drops_each_element_once() {
+        let drops = Rc::new(Cell::new(0));
+        let mut o = OptimizedVec::from(counters(&drops, 6));
+
+        // Each front remove destructs its element in place; the fourth pushes
+        // `first` past `inner.len() / 2`, triggering a single compaction.
+        for _ in 0..4 {
+            o.remove(0);
+        }
+        assert_eq!(o.first, 0);
+        assert_eq!(o.len(), 2);
+        assert_eq!(drops.get(), 4);
+
+        // The two survivors run their destructor exactly once on drop -- never
+        // the orphaned region a second time.
+        drop(o);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn shrink_to_fit_compacts_without_double_drop() {
+        let drops = Rc::new(Cell::new(0));
+        let mut o = OptimizedVec::from(counters(&drops, 4));
+        o.remove(0);
+        o.remove(0);
+        o.shrink_to_fit();
+        assert_eq!(o.first, 0);
+        assert_eq!(o.len(), 2);
+        assert_eq!(drops.get(), 2);
+        assert!(o.capacity() >= o.len());
+        drop(o);
+        assert_eq!(drops.get(), 4);
+    }
 
+    #[test]
+    fn drain_yields_range_in_order_and_repairs_source() {
+        let mut o = OptimizedVec::from(vec![0, 1, 2, 3, 4, 5]);
+        let drained: Vec<_> = o.drain(1..4).collect();
+        assert_eq!(drained, vec![1, 2, 3]);
+        assert_eq!(o.iter().cloned().collect::<Vec<_>>(), vec![0, 4, 5]);
+    }
+
+    #[test]
+    fn drain_respects_shifted_front() {
+        let mut o = OptimizedVec::from(vec![0, 1, 2, 3, 4]);
+        o.remove(0); // logical view is now [1, 2, 3, 4]
+        let drained: Vec<_> = o.drain(..2).collect();
+        assert_eq!(drained, vec![1, 2]);
+        assert_eq!(o.iter().cloned().collect::<Vec<_>>(), vec![3, 4]);
+    }
+
+    #[test]
+    fn drain_dropped_early_drops_remainder_once() {
+        let drops = Rc::new(Cell::new(0));
+        let mut o = OptimizedVec::from(counters(&drops, 6));
+        {
+            let mut d = o.drain(1..5);
+            // Take only the first of the four drained elements, then drop.
+            assert!(d.next().is_some());
+        }
+        // One taken element plus three unconsumed ones have now been destructed.
+        assert_eq!(drops.get(), 4);
+        // The two survivors remain and are dropped exactly once at the end.
+        assert_eq!(o.len(), 2);
+        drop(o);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn into_iter_moves_each_element_once() {
+        let drops = Rc::new(Cell::new(0));
+        let mut o = OptimizedVec::from(counters(&drops, 4));
+        o.remove(0);
+        o.remove(0);
+        let collected: Vec<_> = o.into_iter().collect();
+        assert_eq!(collected.len(), 2);
+        assert_eq!(drops.get(), 2); // the two orphaned elements
+        drop(collected);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn try_reserve_reports_capacity_overflow() {
+        let mut o = OptimizedVec::<u64>::new();
+        assert_eq!(
+            o.try_reserve(usize::MAX),
+            Err(TryReserveError::CapacityOverflow)
+        );
+        // A sane request still goes through and leaves the structure usable.
+        assert_eq!(o.try_reserve(8), Ok(()));
+        assert!(o.capacity() >= 8);
+    }
+
+    #[test]
+    fn reserve_grows_but_never_shrinks() {
+        let mut o = OptimizedVec::<i32>::new();
+        o.reserve(16);
+        let cap = o.capacity();
+        assert!(cap >= 16);
+        // A smaller request must not give capacity back.
+        o.reserve(4);
+        assert!(o.capacity() >= cap);
+        o.reserve_exact(cap + 8);
+        assert!(o.capacity() >= cap + 8);
+    }
 }